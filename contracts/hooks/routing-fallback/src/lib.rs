@@ -1,11 +1,11 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure_eq, wasm_execute, Addr, Deps, DepsMut, Env, Event, HexBinary, MessageInfo,
-    QueryResponse, Response, StdError, Storage,
+    ensure_eq, wasm_execute, Addr, BankMsg, Coin, Deps, DepsMut, Env, Event, HexBinary,
+    MessageInfo, QueryResponse, Response, StdError, Storage,
 };
 
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use hpl_interface::{
     hook::{
         self,
@@ -27,6 +27,15 @@ pub enum ContractError {
 
     #[error("unauthorized")]
     Unauthorized {},
+
+    #[error("paused")]
+    Paused {},
+
+    #[error("insufficient hook payment: wanted {wanted:?}, received {received:?}")]
+    HookPayment {
+        wanted: Vec<Coin>,
+        received: Vec<Coin>,
+    },
 }
 
 // version info for migration info
@@ -36,6 +45,15 @@ pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const FALLBACK_HOOK_KEY: &str = "fallback_hook";
 pub const FALLBACK_HOOK: Item<Addr> = Item::new(FALLBACK_HOOK_KEY);
 
+pub const PAUSED_KEY: &str = "paused";
+pub const PAUSED: Item<bool> = Item::new(PAUSED_KEY);
+
+pub const AGGREGATE_ROUTES_KEY: &str = "aggregate_routes";
+pub const AGGREGATE_ROUTES: Map<u32, Vec<Addr>> = Map::new(AGGREGATE_ROUTES_KEY);
+
+pub const MAILBOX_KEY: &str = "mailbox";
+pub const MAILBOX: Item<Addr> = Item::new(MAILBOX_KEY);
+
 fn new_event(name: &str) -> Event {
     Event::new(format!("hpl_hook_routing::{}", name))
 }
@@ -53,6 +71,13 @@ pub fn instantiate(
 
     hpl_ownable::initialize(deps.storage, &owner)?;
 
+    PAUSED.save(deps.storage, &false)?;
+
+    if let Some(mailbox) = msg.mailbox {
+        let mailbox = deps.api.addr_validate(&mailbox)?;
+        MAILBOX.save(deps.storage, &mailbox)?;
+    }
+
     Ok(Response::new().add_event(
         new_event("initialize")
             .add_attribute("sender", info.sender)
@@ -88,6 +113,94 @@ pub fn execute(
                     .add_attribute("fallback-hook", hook),
             ))
         }
+        ExecuteMsg::SetMailbox { mailbox } => {
+            ensure_eq!(
+                get_owner(deps.storage)?,
+                info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            let mailbox = deps.api.addr_validate(&mailbox)?;
+
+            MAILBOX.save(deps.storage, &mailbox)?;
+
+            Ok(Response::new().add_event(
+                new_event("set_mailbox")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("mailbox", mailbox),
+            ))
+        }
+        ExecuteMsg::SweepFunds { recipient } => {
+            ensure_eq!(
+                get_owner(deps.storage)?,
+                info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            let recipient = deps.api.addr_validate(&recipient)?;
+
+            let balance = deps
+                .querier
+                .query_all_balances(env.contract.address.as_str())?;
+
+            let mut resp = Response::new().add_event(
+                new_event("sweep_funds")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("recipient", recipient.clone()),
+            );
+
+            if !balance.is_empty() {
+                resp = resp.add_message(BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: balance,
+                });
+            }
+
+            Ok(resp)
+        }
+        ExecuteMsg::SetAggregateRoutes { domain, hooks } => {
+            ensure_eq!(
+                get_owner(deps.storage)?,
+                info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            let hooks = hooks
+                .iter()
+                .map(|hook| deps.api.addr_validate(hook))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            AGGREGATE_ROUTES.save(deps.storage, domain, &hooks)?;
+
+            Ok(Response::new().add_event(
+                new_event("set_aggregate_routes")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("domain", domain.to_string())
+                    .add_attribute(
+                        "hooks",
+                        hooks
+                            .iter()
+                            .map(Addr::to_string)
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    ),
+            ))
+        }
+        ExecuteMsg::SetPaused { paused } => {
+            ensure_eq!(
+                get_owner(deps.storage)?,
+                info.sender,
+                ContractError::Unauthorized {}
+            );
+
+            PAUSED.save(deps.storage, &paused)?;
+
+            Ok(Response::new().add_event(
+                new_event("set_paused")
+                    .add_attribute("sender", info.sender)
+                    .add_attribute("paused", paused.to_string()),
+            ))
+        }
     }
 }
 
@@ -100,40 +213,156 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<QueryResponse, Contr
             HookQueryMsg::Mailbox {} => to_binary(get_mailbox(deps)),
             HookQueryMsg::QuoteDispatch(msg) => to_binary(quote_dispatch(deps, msg)),
         },
+        QueryMsg::Paused {} => to_binary(get_paused(deps)),
     }
 }
 
-fn get_mailbox(_deps: Deps) -> Result<MailboxResponse, ContractError> {
-    Ok(MailboxResponse {
-        mailbox: "unrestricted".to_string(),
-    })
+fn get_paused(deps: Deps) -> Result<bool, ContractError> {
+    Ok(PAUSED.load(deps.storage)?)
+}
+
+fn get_mailbox(deps: Deps) -> Result<MailboxResponse, ContractError> {
+    let mailbox = MAILBOX
+        .may_load(deps.storage)?
+        .map(Addr::into_string)
+        .unwrap_or_else(|| "unrestricted".to_string());
+
+    Ok(MailboxResponse { mailbox })
 }
 
-fn route(storage: &dyn Storage, message: &HexBinary) -> Result<(Message, Addr), ContractError> {
+fn route(
+    storage: &dyn Storage,
+    message: &HexBinary,
+) -> Result<(Message, Vec<Addr>), ContractError> {
     let decoded_msg: Message = message.clone().into();
     let dest_domain = decoded_msg.dest_domain;
 
+    if let Some(aggregate_hooks) = AGGREGATE_ROUTES.may_load(storage, dest_domain)? {
+        if !aggregate_hooks.is_empty() {
+            return Ok((decoded_msg, aggregate_hooks));
+        }
+    }
+
     let fallback_hook = FALLBACK_HOOK.load(storage)?;
 
     let routed_hook_set = hpl_router::get_route::<Addr>(storage, dest_domain)?;
     let routed_hook = routed_hook_set.route.unwrap_or(fallback_hook);
 
-    Ok((decoded_msg, routed_hook))
+    Ok((decoded_msg, vec![routed_hook]))
+}
+
+fn add_coin(coins: &mut Vec<Coin>, coin: &Coin) -> Result<(), ContractError> {
+    match coins.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(existing) => {
+            existing.amount = existing
+                .amount
+                .checked_add(coin.amount)
+                .map_err(StdError::from)?;
+        }
+        None => coins.push(coin.clone()),
+    }
+
+    Ok(())
+}
+
+fn amount_of(coins: &[Coin], denom: &str) -> cosmwasm_std::Uint128 {
+    coins
+        .iter()
+        .find(|c| c.denom == denom)
+        .map(|c| c.amount)
+        .unwrap_or_default()
+}
+
+fn ensure_payment(wanted: &[Coin], received: &[Coin]) -> Result<(), ContractError> {
+    for want in wanted {
+        if amount_of(received, &want.denom) < want.amount {
+            return Err(ContractError::HookPayment {
+                wanted: wanted.to_vec(),
+                received: received.to_vec(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn refund_of(received: &[Coin], wanted: &[Coin]) -> Vec<Coin> {
+    received
+        .iter()
+        .filter_map(|c| {
+            let remainder = c.amount.saturating_sub(amount_of(wanted, &c.denom));
+            (!remainder.is_zero()).then(|| Coin {
+                denom: c.denom.clone(),
+                amount: remainder,
+            })
+        })
+        .collect()
 }
 
 pub fn post_dispatch(
     deps: DepsMut,
-    _info: MessageInfo,
+    info: MessageInfo,
     req: PostDispatchMsg,
 ) -> Result<Response, ContractError> {
-    let (decoded_msg, routed_hook) = route(deps.storage, &req.message)?;
+    let mailbox = MAILBOX.may_load(deps.storage)?;
+    if let Some(mailbox) = &mailbox {
+        ensure_eq!(mailbox, &info.sender, ContractError::Unauthorized {});
+    }
+
+    if PAUSED.load(deps.storage)? {
+        return Err(ContractError::Paused {});
+    }
+
+    let (decoded_msg, routed_hooks) = route(deps.storage, &req.message)?;
 
-    let hook_msg = wasm_execute(&routed_hook, &req.wrap(), vec![])?;
+    let mut wanted: Vec<Coin> = vec![];
+    let mut per_hook_fees = Vec::with_capacity(routed_hooks.len());
+    for hook in &routed_hooks {
+        let quoted = hook::quote_dispatch(
+            &deps.querier,
+            hook.as_str(),
+            req.metadata.clone(),
+            req.message.clone(),
+        )?;
 
-    Ok(Response::new().add_message(hook_msg).add_event(
+        for fee in &quoted.fees {
+            add_coin(&mut wanted, fee)?;
+        }
+        per_hook_fees.push(quoted.fees);
+    }
+
+    ensure_payment(&wanted, &info.funds)?;
+
+    let mut resp = Response::new();
+    for (hook, fees) in routed_hooks.iter().zip(per_hook_fees) {
+        resp = resp.add_message(wasm_execute(hook, &req.wrap(), fees)?);
+    }
+
+    // `info.sender` is only the original payer when no mailbox is bound — once one is
+    // bound, post_dispatch can only be called by the Mailbox itself (checked above), so
+    // refunding to `info.sender` there would send the excess to the Mailbox, not the
+    // account that attached the funds to `dispatch()`.
+    if mailbox.is_none() {
+        let refund = refund_of(&info.funds, &wanted);
+        if !refund.is_empty() {
+            resp = resp.add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: refund,
+            });
+        }
+    }
+
+    Ok(resp.add_event(
         new_event("post_dispatch")
             .add_attribute("domain", decoded_msg.dest_domain.to_string())
-            .add_attribute("route", routed_hook)
+            .add_attribute(
+                "route",
+                routed_hooks
+                    .iter()
+                    .map(Addr::to_string)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
             .add_attribute("message_id", decoded_msg.id().to_hex()),
     ))
 }
@@ -142,14 +371,23 @@ pub fn quote_dispatch(
     deps: Deps,
     req: QuoteDispatchMsg,
 ) -> Result<QuoteDispatchResponse, ContractError> {
-    let (_, routed_hook) = route(deps.storage, &req.message)?;
+    let (_, routed_hooks) = route(deps.storage, &req.message)?;
+
+    let mut fees: Vec<Coin> = vec![];
+    for hook in &routed_hooks {
+        let resp = hook::quote_dispatch(
+            &deps.querier,
+            hook.as_str(),
+            req.metadata.clone(),
+            req.message.clone(),
+        )?;
+
+        for fee in &resp.fees {
+            add_coin(&mut fees, fee)?;
+        }
+    }
 
-    let resp = hook::quote_dispatch(
-        &deps.querier,
-        routed_hook.as_str(),
-        req.metadata,
-        req.message,
-    )?;
+    let resp = QuoteDispatchResponse { fees };
 
     Ok(resp)
 }
@@ -226,6 +464,7 @@ mod test {
             mock_info(sender.as_str(), &[]),
             InstantiateMsg {
                 owner: owner.to_string(),
+                mailbox: None,
             },
         )
         .unwrap();
@@ -277,6 +516,168 @@ mod test {
         assert_eq!("unrestricted", res.mailbox);
     }
 
+    #[rstest]
+    fn test_set_mailbox_unauthorized(mut deps: TestDeps) {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEPLOYER, &[]),
+            ExecuteMsg::SetMailbox {
+                mailbox: MAILBOX.to_string(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err);
+    }
+
+    #[rstest]
+    fn test_sweep_funds_unauthorized(mut deps: TestDeps) {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEPLOYER, &[]),
+            ExecuteMsg::SweepFunds {
+                recipient: OWNER.to_string(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err);
+    }
+
+    #[rstest]
+    fn test_sweep_funds(mut deps: TestDeps) {
+        let env = mock_env();
+        deps.querier
+            .update_balance(env.contract.address.as_str(), vec![coin(100, "utest")]);
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SweepFunds {
+                recipient: DEPLOYER.to_string(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(1, res.messages.len());
+
+        match &res.messages[0].msg {
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(DEPLOYER, to_address);
+                assert_eq!(vec![coin(100, "utest")], *amount);
+            }
+            _ => panic!("expected a sweep bank message"),
+        }
+    }
+
+    #[rstest]
+    fn test_post_dispatch_bound_mailbox(deps_routes: (TestDeps, Routes)) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetMailbox {
+                mailbox: MAILBOX.to_string(),
+            },
+        )
+        .unwrap();
+
+        let res: MailboxResponse =
+            test_query(deps.as_ref(), QueryMsg::Hook(HookQueryMsg::Mailbox {}));
+        assert_eq!(MAILBOX, res.mailbox);
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let err = post_dispatch(
+            deps.as_mut(),
+            mock_info(DEPLOYER, &[]),
+            PostDispatchMsg {
+                metadata: HexBinary::default(),
+                message: rand_msg.clone().into(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err);
+
+        post_dispatch(
+            deps.as_mut(),
+            mock_info(MAILBOX, &[]),
+            PostDispatchMsg {
+                metadata: HexBinary::default(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[fixture]
+    fn deps_paused(mut deps: TestDeps, #[default(addr(OWNER))] sender: Addr) -> TestDeps {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender.as_str(), &[]),
+            ExecuteMsg::SetPaused { paused: true },
+        )
+        .unwrap();
+
+        deps
+    }
+
+    #[rstest]
+    fn test_set_paused_unauthorized(mut deps: TestDeps) {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEPLOYER, &[]),
+            ExecuteMsg::SetPaused { paused: true },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err);
+    }
+
+    #[rstest]
+    fn test_post_dispatch_paused(deps_paused: TestDeps) {
+        let mut deps = deps_paused;
+        deps.querier.update_wasm(mock_query_handler);
+
+        let res: bool = test_query(deps.as_ref(), QueryMsg::Paused {});
+        assert!(res);
+
+        let rand_msg: Message = gen_bz(100).into();
+
+        let err = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[]),
+            PostDispatchMsg {
+                metadata: HexBinary::default(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Paused {}, err);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetPaused { paused: false },
+        )
+        .unwrap();
+
+        let res: bool = test_query(deps.as_ref(), QueryMsg::Paused {});
+        assert!(!res);
+    }
+
     #[rstest]
     #[case(MAILBOX, ROUTE1)]
     #[case(OWNER, (12345, FALLBACK_HOOK))]
@@ -286,6 +687,7 @@ mod test {
         #[case] route: Route,
     ) {
         let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
 
         let mut rand_msg: Message = gen_bz(100).into();
         rand_msg.dest_domain = route.0;
@@ -311,6 +713,271 @@ mod test {
         assert_eq!(route.1, event.attributes[1].value);
     }
 
+    fn mock_query_handler_multi_denom(req: &WasmQuery) -> QuerierResult {
+        match req {
+            WasmQuery::Smart { .. } => (),
+            _ => unreachable!("wrong query type"),
+        };
+
+        let res = QuoteDispatchResponse {
+            fees: vec![coin(100, "utest"), coin(50, "uatom")],
+        };
+        let res = cosmwasm_std::to_binary(&res).unwrap();
+        SystemResult::Ok(ContractResult::Ok(res))
+    }
+
+    #[rstest]
+    fn test_post_dispatch_insufficient_payment(deps_routes: (TestDeps, Routes)) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let err = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[]),
+            PostDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            ContractError::HookPayment {
+                wanted: vec![coin(100, "utest")],
+                received: vec![],
+            },
+            err
+        );
+    }
+
+    #[rstest]
+    fn test_post_dispatch_exact_payment(deps_routes: (TestDeps, Routes)) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let res = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[coin(100, "utest")]),
+            PostDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(1, res.messages.len());
+    }
+
+    #[rstest]
+    fn test_post_dispatch_overpayment_refund(deps_routes: (TestDeps, Routes)) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let res = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[coin(150, "utest")]),
+            PostDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(2, res.messages.len());
+
+        match &res.messages[1].msg {
+            cosmwasm_std::CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(OWNER, to_address);
+                assert_eq!(vec![coin(50, "utest")], *amount);
+            }
+            _ => panic!("expected a refund bank message"),
+        }
+    }
+
+    #[rstest]
+    fn test_post_dispatch_bound_mailbox_overpayment_not_refunded(
+        deps_routes: (TestDeps, Routes),
+    ) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetMailbox {
+                mailbox: MAILBOX.to_string(),
+            },
+        )
+        .unwrap();
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        // `info.sender` here is the Mailbox, not the account that actually attached the
+        // funds to `dispatch()` — confirm the overpayment is never sent to it.
+        let res = post_dispatch(
+            deps.as_mut(),
+            mock_info(MAILBOX, &[coin(150, "utest")]),
+            PostDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(1, res.messages.len());
+    }
+
+    #[rstest]
+    fn test_post_dispatch_multiple_denoms(deps_routes: (TestDeps, Routes)) {
+        let (mut deps, _) = deps_routes;
+        deps.querier.update_wasm(mock_query_handler_multi_denom);
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let res = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[coin(100, "utest"), coin(50, "uatom")]),
+            PostDispatchMsg {
+                metadata: HexBinary::default(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(1, res.messages.len());
+    }
+
+    #[rstest]
+    fn test_set_aggregate_routes_unauthorized(mut deps: TestDeps) {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEPLOYER, &[]),
+            ExecuteMsg::SetAggregateRoutes {
+                domain: ROUTE1.0,
+                hooks: vec!["hook1".to_string(), "hook2".to_string()],
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err);
+    }
+
+    #[rstest]
+    fn test_post_dispatch_aggregate_routes(mut deps: TestDeps) {
+        deps.querier.update_wasm(mock_query_handler);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetAggregateRoutes {
+                domain: ROUTE1.0,
+                hooks: vec!["hook1".to_string(), "hook2".to_string()],
+            },
+        )
+        .unwrap();
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let res = post_dispatch(
+            deps.as_mut(),
+            mock_info(OWNER, &[coin(200, "utest")]),
+            PostDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(2, res.messages.len());
+    }
+
+    #[rstest]
+    fn test_quote_dispatch_aggregate_routes(mut deps: TestDeps) {
+        deps.querier.update_wasm(mock_query_handler);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetAggregateRoutes {
+                domain: ROUTE1.0,
+                hooks: vec!["hook1".to_string(), "hook2".to_string()],
+            },
+        )
+        .unwrap();
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let res: QuoteDispatchResponse = test_query(
+            deps.as_ref(),
+            QueryMsg::Hook(HookQueryMsg::QuoteDispatch(QuoteDispatchMsg {
+                metadata: 100u32.to_be_bytes().to_vec().into(),
+                message: rand_msg.into(),
+            })),
+        );
+
+        assert_eq!(Some(200), res.fees.first().map(|v| v.amount.u128() as u32));
+    }
+
+    fn mock_query_handler_max_fee(req: &WasmQuery) -> QuerierResult {
+        match req {
+            WasmQuery::Smart { .. } => (),
+            _ => unreachable!("wrong query type"),
+        };
+
+        let res = QuoteDispatchResponse {
+            fees: vec![coin(u128::MAX, "utest")],
+        };
+        let res = cosmwasm_std::to_binary(&res).unwrap();
+        SystemResult::Ok(ContractResult::Ok(res))
+    }
+
+    #[rstest]
+    fn test_quote_dispatch_aggregate_routes_overflow_is_clean_error(mut deps: TestDeps) {
+        deps.querier.update_wasm(mock_query_handler_max_fee);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::SetAggregateRoutes {
+                domain: ROUTE1.0,
+                hooks: vec!["hook1".to_string(), "hook2".to_string()],
+            },
+        )
+        .unwrap();
+
+        let mut rand_msg: Message = gen_bz(100).into();
+        rand_msg.dest_domain = ROUTE1.0;
+
+        let err = quote_dispatch(
+            deps.as_ref(),
+            QuoteDispatchMsg {
+                metadata: HexBinary::default(),
+                message: rand_msg.into(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, ContractError::Std(_)));
+    }
+
     #[rstest]
     #[case(26657, Some(26657))]
     #[case(12345, None)]